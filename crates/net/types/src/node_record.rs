@@ -129,6 +129,14 @@ pub enum NodeRecordParseError {
     /// Invalid discport
     #[error("Failed to discport query: {0}")]
     Discport(ParseIntError),
+    /// Invalid node in a list of nodes
+    #[error("Failed to parse node {node}: {error}")]
+    InvalidNode {
+        /// The node string that failed to parse
+        node: String,
+        /// The underlying parse error
+        error: Box<NodeRecordParseError>,
+    },
 }
 
 impl FromStr for NodeRecord {
@@ -114,8 +114,29 @@ pub fn base_testnet_nodes() -> Vec<NodeRecord> {
 }
 
 /// Parses all the nodes
+///
+/// # Panics
+///
+/// If any of the nodes is not a valid [`NodeRecord`]. This is intended for the built-in bootnode
+/// lists, use [`try_parse_nodes`] for user provided nodes.
 pub fn parse_nodes(nodes: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<NodeRecord> {
-    nodes.into_iter().map(|s| s.as_ref().parse().unwrap()).collect()
+    try_parse_nodes(nodes).expect("valid bootnodes")
+}
+
+/// Parses all the nodes, returning an error that contains the first node that failed to parse.
+pub fn try_parse_nodes(
+    nodes: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Result<Vec<NodeRecord>, NodeRecordParseError> {
+    nodes
+        .into_iter()
+        .map(|s| {
+            let s = s.as_ref();
+            s.parse().map_err(|err| NodeRecordParseError::InvalidNode {
+                node: s.to_string(),
+                error: Box::new(err),
+            })
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -212,6 +233,20 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_try_parse_nodes() {
+        let good = "enode://6f8a80d14311c39f35f516fa664deaaaa13e85b2f7493f37f6144d86991ec012937307647bd3b9a82abe2974e1407241d54947bbb39763a4cac9f77166ad92a0@10.3.58.6:30303";
+        let bad = "enode://not-a-peer-id@10.3.58.6:30303";
+
+        assert_eq!(try_parse_nodes([good]).unwrap(), vec![good.parse().unwrap()]);
+
+        let err = try_parse_nodes([good, bad]).unwrap_err();
+        assert!(matches!(
+            err,
+            NodeRecordParseError::InvalidNode { ref node, .. } if node == bad
+        ));
+    }
+
     #[test]
     fn test_node_display() {
         let url = "enode://6f8a80d14311c39f35f516fa664deaaaa13e85b2f7493f37f6144d86991ec012937307647bd3b9a82abe2974e1407241d54947bbb39763a4cac9f77166ad92a0@10.3.58.6:30303";